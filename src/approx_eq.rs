@@ -0,0 +1,67 @@
+//! Approximate equality for the floating-point types used throughout
+//! this crate.
+//!
+//! `Vector3` derives `PartialEq`, but exact float comparison is rarely
+//! what callers actually want once trigonometric round-trips are
+//! involved (see the `FIXME` on `From<Cylindrical>`). This trait adds a
+//! relative/epsilon comparison and an ULP-based one, so round-trip
+//! conversions can be asserted correct without false negatives from the
+//! unavoidable trig error.
+
+/// Approximate equality with an explicit tolerance.
+pub trait ApproxEq<Rhs = Self> {
+    /// The scalar type `epsilon`/`max_relative` are expressed in. For a
+    /// composite type like `Vector3<T>` this is the component type `T`,
+    /// not `Self`.
+    type Eps;
+
+    /// Component-wise relative comparison: `|a - b| <= max(|a|, |b|) * max_relative`,
+    /// falling back to the absolute `epsilon` for values near zero.
+    fn approx_eq(&self, other: &Rhs, epsilon: Self::Eps, max_relative: Self::Eps) -> bool;
+
+    /// Comparison by ULP (unit in the last place) distance.
+    fn approx_eq_ulps(&self, other: &Rhs, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_approx_eq_float {
+    ($float: ty, $int: ty) => {
+        impl ApproxEq for $float {
+            type Eps = $float;
+
+            fn approx_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool {
+                let diff = (self - other).abs();
+                if diff <= epsilon {
+                    return true;
+                }
+                diff <= self.abs().max(other.abs()) * max_relative
+            }
+
+            fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+                if self == other {
+                    return true;
+                }
+
+                // Map the sign-magnitude bit pattern to a monotonic integer
+                // ordering (flip everything but the sign bit when negative)
+                // so plain integer subtraction yields the ULP distance.
+                fn ordered(bits: $int) -> $int {
+                    if bits < 0 {
+                        <$int>::MIN.wrapping_sub(bits)
+                    } else {
+                        bits
+                    }
+                }
+
+                let a = ordered(self.to_bits() as $int);
+                let b = ordered(other.to_bits() as $int);
+                a.wrapping_sub(b).unsigned_abs() <= max_ulps.into()
+            }
+        }
+    };
+}
+
+impl_approx_eq_float!(f32, i32);
+impl_approx_eq_float!(f64, i64);
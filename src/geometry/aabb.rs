@@ -0,0 +1,26 @@
+//! Axis-aligned bounding boxes.
+
+use num_traits::Float;
+
+use crate::three_dimensional::vector3::Vector3;
+
+/// An axis-aligned bounding box spanning from `min` to `max`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb3<T: Float> {
+    /// The corner with the smallest coordinate on every axis.
+    pub min: Vector3<T>,
+    /// The corner with the largest coordinate on every axis.
+    pub max: Vector3<T>,
+}
+
+impl<T: Float> Aabb3<T> {
+    /// Whether `point` lies within `self`, inclusive of the boundary.
+    pub fn contains(&self, point: &Vector3<T>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+}
@@ -0,0 +1,13 @@
+//! Geometric primitives and the intersection queries between them, built
+//! on top of [`crate::three_dimensional::vector3::Vector3`].
+//!
+//! Mirrors fyrox-core's `ray`/`plane`/`aabb` split: useful for picking
+//! and collision once a scene is made of more than bare points.
+
+pub mod aabb;
+pub mod plane;
+pub mod ray;
+
+pub use aabb::Aabb3;
+pub use plane::Plane;
+pub use ray::Ray3;
@@ -0,0 +1,16 @@
+//! Infinite planes.
+
+use num_traits::Float;
+
+use crate::three_dimensional::vector3::Vector3;
+
+/// An infinite plane satisfying `normal \cdot p + d == 0` for every point
+/// `p` on it. `normal` is expected to be unit length.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Plane<T: Float> {
+    /// The plane's unit normal.
+    pub normal: Vector3<T>,
+    /// The negated signed distance from the origin along `normal`: the
+    /// plane passes through `-d * normal`.
+    pub d: T,
+}
@@ -0,0 +1,322 @@
+//! Rays and the intersection queries built on top of them.
+
+use num_traits::Float;
+
+use crate::ops::Ops;
+use crate::traits::Dot;
+
+use crate::three_dimensional::vector3::Vector3;
+
+use super::aabb::Aabb3;
+use super::plane::Plane;
+
+/// A half-line starting at `origin` and extending along `direction`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ray3<T: Float> {
+    /// The point the ray starts from.
+    pub origin: Vector3<T>,
+    /// The direction the ray travels in. Need not be normalized; the
+    /// returned parametric distance `t` is in units of this vector's
+    /// length.
+    pub direction: Vector3<T>,
+}
+
+impl<T: Float> Ray3<T> {
+    /// The point reached by travelling `t` units of `self.direction` from `self.origin`.
+    pub fn at(&self, t: T) -> Vector3<T> {
+        self.origin + self.direction * t
+    }
+
+    /// Parametric distance to `plane`, or `None` if the ray is parallel to
+    /// it or the intersection lies behind the origin.
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<T> {
+        let denom = plane.normal.dot(&self.direction);
+        if denom.abs() <= T::epsilon() {
+            return None;
+        }
+        let t = -(plane.normal.dot(&self.origin) + plane.d) / denom;
+        (t >= T::zero()).then_some(t)
+    }
+
+    /// Parametric distance to the nearest intersection with the sphere of
+    /// `radius` centered at `center`, or `None` if the ray misses it.
+    ///
+    /// Uses the full quadratic (rather than the unit-direction
+    /// simplification) so a non-normalized `direction`, which this type's
+    /// other intersection methods allow, still gives a correct `t`.
+    pub fn intersect_sphere(&self, center: &Vector3<T>, radius: T) -> Option<T>
+    where
+        T: Ops,
+    {
+        let oc = self.origin - *center;
+        let a = self.direction.dot(&self.direction);
+        let b = oc.dot(&self.direction);
+        let cc = oc.dot(&oc) - radius * radius;
+        let discriminant = b * b - a * cc;
+        if discriminant < T::zero() {
+            return None;
+        }
+        let sqrt_d = discriminant.ops_sqrt();
+        let nearest = (-b - sqrt_d) / a;
+        let farthest = (-b + sqrt_d) / a;
+        let t = if nearest >= T::zero() {
+            nearest
+        } else {
+            farthest
+        };
+        (t >= T::zero()).then_some(t)
+    }
+
+    /// Parametric distance to the nearest intersection with `aabb`, using
+    /// the slab method, or `None` if the ray misses it.
+    pub fn intersect_aabb(&self, aabb: &Aabb3<T>) -> Option<T> {
+        let mut t_min = T::neg_infinity();
+        let mut t_max = T::infinity();
+
+        for axis in 0..3 {
+            let origin = component(&self.origin, axis);
+            let direction = component(&self.direction, axis);
+            let min = component(&aabb.min, axis);
+            let max = component(&aabb.max, axis);
+
+            if direction == T::zero() {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let mut t1 = (min - origin) / direction;
+            let mut t2 = (max - origin) / direction;
+            if t1 > t2 {
+                std::mem::swap(&mut t1, &mut t2);
+            }
+            t_min = t_min.max(t1);
+            t_max = t_max.min(t2);
+        }
+
+        let t_min = t_min.max(T::zero());
+        (t_max >= t_min).then_some(t_min)
+    }
+}
+
+fn component<T: Float>(vector: &Vector3<T>, axis: usize) -> T {
+    match axis {
+        0 => vector.x,
+        1 => vector.y,
+        _ => vector.z,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Aabb3, Plane, Ray3};
+    use crate::three_dimensional::vector3::Vector3;
+
+    #[test]
+    pub fn plane_hit() {
+        let ray = Ray3 {
+            origin: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 5.0,
+            },
+            direction: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            },
+        };
+        let plane = Plane {
+            normal: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            d: 0.0,
+        };
+
+        assert_eq!(ray.intersect_plane(&plane), Some(5.0));
+    }
+
+    #[test]
+    pub fn plane_parallel_misses() {
+        let ray = Ray3 {
+            origin: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 5.0,
+            },
+            direction: Vector3 {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        };
+        let plane = Plane {
+            normal: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            d: 0.0,
+        };
+
+        assert_eq!(ray.intersect_plane(&plane), None);
+    }
+
+    #[test]
+    pub fn plane_behind_origin_misses() {
+        let ray = Ray3 {
+            origin: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: -5.0,
+            },
+            direction: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            },
+        };
+        let plane = Plane {
+            normal: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            d: 0.0,
+        };
+
+        assert_eq!(ray.intersect_plane(&plane), None);
+    }
+
+    #[test]
+    pub fn sphere_hit_returns_nearest_root() {
+        let ray = Ray3 {
+            origin: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 5.0,
+            },
+            direction: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            },
+        };
+        let center = Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+
+        assert_eq!(ray.intersect_sphere(&center, 1.0), Some(4.0));
+    }
+
+    #[test]
+    pub fn sphere_tangent_ray_grazes_surface() {
+        let ray = Ray3 {
+            origin: Vector3 {
+                x: 1.0,
+                y: 0.0,
+                z: 5.0,
+            },
+            direction: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: -1.0,
+            },
+        };
+        let center = Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+
+        assert_eq!(ray.intersect_sphere(&center, 1.0), Some(5.0));
+    }
+
+    #[test]
+    pub fn sphere_ray_origin_inside_returns_exit_point() {
+        let ray = Ray3 {
+            origin: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            direction: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+        };
+        let center = Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+
+        assert_eq!(ray.intersect_sphere(&center, 2.0), Some(2.0));
+    }
+
+    #[test]
+    pub fn aabb_hit_with_zero_direction_components_in_slab() {
+        let ray = Ray3 {
+            origin: Vector3 {
+                x: -5.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            direction: Vector3 {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        };
+        let aabb = Aabb3 {
+            min: Vector3 {
+                x: -1.0,
+                y: -1.0,
+                z: -1.0,
+            },
+            max: Vector3 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+        };
+
+        assert_eq!(ray.intersect_aabb(&aabb), Some(4.0));
+    }
+
+    #[test]
+    pub fn aabb_miss_with_zero_direction_component_outside_slab() {
+        let ray = Ray3 {
+            origin: Vector3 {
+                x: -5.0,
+                y: 5.0,
+                z: 0.0,
+            },
+            direction: Vector3 {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        };
+        let aabb = Aabb3 {
+            min: Vector3 {
+                x: -1.0,
+                y: -1.0,
+                z: -1.0,
+            },
+            max: Vector3 {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+        };
+
+        assert_eq!(ray.intersect_aabb(&aabb), None);
+    }
+}
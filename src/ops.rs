@@ -0,0 +1,115 @@
+//! Deterministic dispatch for the handful of floating point operations
+//! (`sqrt`, `acos`, `sin_cos`, `atan2`) whose precision is left unspecified by
+//! `std` and can differ bit-for-bit between platforms and Rust versions.
+//!
+//! With the `libm` feature enabled these route through the pure-Rust
+//! `libm` crate instead, which is the same on every target. Without it
+//! we fall straight through to the `std` methods so there's no cost for
+//! users who don't need reproducibility.
+
+use num_traits::Float;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Sealed trait dispatching to either `std` or `libm` for the float
+/// operations used in this crate's trig/root-heavy code.
+///
+/// `T: Float` alone doesn't tell generic code which concrete type it
+/// has, so this is implemented directly for `f32` and `f64` and pulled
+/// in through an additional `T: Ops` bound wherever the choice matters.
+pub trait Ops: Float + private::Sealed {
+    /// Deterministic square root.
+    fn ops_sqrt(self) -> Self;
+    /// Deterministic arc-cosine, in radians.
+    fn ops_acos(self) -> Self;
+    /// Deterministic simultaneous sine and cosine, in radians.
+    fn ops_sin_cos(self) -> (Self, Self);
+    /// Deterministic two-argument arc-tangent, in radians.
+    fn ops_atan2(self, other: Self) -> Self;
+}
+
+#[cfg(not(feature = "libm"))]
+mod imp {
+    use super::Ops;
+
+    impl Ops for f32 {
+        fn ops_sqrt(self) -> Self {
+            self.sqrt()
+        }
+
+        fn ops_acos(self) -> Self {
+            self.acos()
+        }
+
+        fn ops_sin_cos(self) -> (Self, Self) {
+            self.sin_cos()
+        }
+
+        fn ops_atan2(self, other: Self) -> Self {
+            self.atan2(other)
+        }
+    }
+
+    impl Ops for f64 {
+        fn ops_sqrt(self) -> Self {
+            self.sqrt()
+        }
+
+        fn ops_acos(self) -> Self {
+            self.acos()
+        }
+
+        fn ops_sin_cos(self) -> (Self, Self) {
+            self.sin_cos()
+        }
+
+        fn ops_atan2(self, other: Self) -> Self {
+            self.atan2(other)
+        }
+    }
+}
+
+#[cfg(feature = "libm")]
+mod imp {
+    use super::Ops;
+
+    impl Ops for f32 {
+        fn ops_sqrt(self) -> Self {
+            libm::sqrtf(self)
+        }
+
+        fn ops_acos(self) -> Self {
+            libm::acosf(self)
+        }
+
+        fn ops_sin_cos(self) -> (Self, Self) {
+            libm::sincosf(self)
+        }
+
+        fn ops_atan2(self, other: Self) -> Self {
+            libm::atan2f(self, other)
+        }
+    }
+
+    impl Ops for f64 {
+        fn ops_sqrt(self) -> Self {
+            libm::sqrt(self)
+        }
+
+        fn ops_acos(self) -> Self {
+            libm::acos(self)
+        }
+
+        fn ops_sin_cos(self) -> (Self, Self) {
+            libm::sincos(self)
+        }
+
+        fn ops_atan2(self, other: Self) -> Self {
+            libm::atan2(self, other)
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use num_traits::Float;
+
+use crate::approx_eq::ApproxEq;
+use crate::ops::Ops;
+
+use super::vector3::Vector3;
+
+#[cfg_attr(serde, derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+/// A point described by a radius from the z-axis, an azimuthal angle
+/// measured from the +x axis, and a height along the z-axis.
+pub struct Cylindrical<T: Float> {
+    /// Distance from the z-axis.
+    pub radius: T,
+    /// Angle, in radians, measured from the +x axis.
+    pub azimuth: T,
+    /// Height along the z-axis.
+    pub height: T,
+}
+
+impl<T: Float + ApproxEq<Eps = T>> ApproxEq for Cylindrical<T> {
+    type Eps = T;
+
+    fn approx_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.radius.approx_eq(&other.radius, epsilon, max_relative)
+            && self.azimuth.approx_eq(&other.azimuth, epsilon, max_relative)
+            && self.height.approx_eq(&other.height, epsilon, max_relative)
+    }
+
+    fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+        self.radius.approx_eq_ulps(&other.radius, max_ulps)
+            && self.azimuth.approx_eq_ulps(&other.azimuth, max_ulps)
+            && self.height.approx_eq_ulps(&other.height, max_ulps)
+    }
+}
+
+/// Converts a cartesian point to cylindrical coordinates. At the origin
+/// `radius` is `0` and `azimuth` falls back to `atan2(0, 0) == 0` rather
+/// than being undefined.
+impl<T: Float + Ops> From<Vector3<T>> for Cylindrical<T> {
+    fn from(vector: Vector3<T>) -> Self {
+        Cylindrical {
+            radius: (vector.x * vector.x + vector.y * vector.y).ops_sqrt(),
+            azimuth: vector.y.ops_atan2(vector.x),
+            height: vector.z,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use num_traits::Float;
+
+use crate::approx_eq::ApproxEq;
+use crate::ops::Ops;
+use crate::traits::Magnitude;
+
+use super::vector3::Vector3;
+
+#[cfg_attr(serde, derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+/// A point described by a radius, an azimuthal angle measured from the
+/// +x axis, and a polar angle measured from the +z axis.
+pub struct Spherical<T: Float> {
+    /// Distance from the origin.
+    pub radius: T,
+    /// Angle, in radians, measured from the +x axis (0 is straight right).
+    pub azimuthal_angle: T,
+    /// Angle, in radians, measured from the +z axis (0 is straight up).
+    pub polar_angle: T,
+}
+
+impl<T: Float + ApproxEq<Eps = T>> ApproxEq for Spherical<T> {
+    type Eps = T;
+
+    fn approx_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.radius.approx_eq(&other.radius, epsilon, max_relative)
+            && self
+                .azimuthal_angle
+                .approx_eq(&other.azimuthal_angle, epsilon, max_relative)
+            && self
+                .polar_angle
+                .approx_eq(&other.polar_angle, epsilon, max_relative)
+    }
+
+    fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+        self.radius.approx_eq_ulps(&other.radius, max_ulps)
+            && self
+                .azimuthal_angle
+                .approx_eq_ulps(&other.azimuthal_angle, max_ulps)
+            && self
+                .polar_angle
+                .approx_eq_ulps(&other.polar_angle, max_ulps)
+    }
+}
+
+/// Converts a cartesian point to spherical coordinates. At the origin
+/// `radius` is `0`, so `polar_angle = acos(z / radius)` divides by zero
+/// and comes out `NaN`; this is an inherent degeneracy of spherical
+/// coordinates at the origin, not a bug in the conversion.
+impl<T: Float + Ops> From<Vector3<T>> for Spherical<T> {
+    fn from(vector: Vector3<T>) -> Self {
+        let radius = vector.magnitude();
+        Spherical {
+            radius,
+            azimuthal_angle: vector.y.ops_atan2(vector.x),
+            polar_angle: (vector.z / radius).ops_acos(),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Phantom-typed coordinate spaces.
+//!
+//! Wraps [`Vector3`] with a zero-sized `Space` marker so vectors that
+//! belong to different coordinate spaces (world, local, screen, ...)
+//! can't be added, subtracted, or otherwise mixed without an explicit
+//! [`TypedVector3::cast_space`] call. Mirrors the approach `euclid` takes
+//! with its typed units.
+
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Neg, Sub};
+
+use num_traits::Float;
+
+use crate::ops::Ops;
+use crate::traits::{Cross3D, Dot, Magnitude};
+
+use super::vector3::Vector3;
+
+/// A [`Vector3`] tagged with the coordinate space it belongs to.
+///
+/// `Space` carries no data; it exists purely so the type checker rejects
+/// arithmetic between vectors from different spaces, such as adding a
+/// world-space position to a camera-local offset.
+pub struct TypedVector3<T: Float, Space> {
+    /// The untyped vector data.
+    pub vector: Vector3<T>,
+    _space: PhantomData<Space>,
+}
+
+// Manual impls: `#[derive]` would require `Space: Debug + Copy + Clone + PartialEq`,
+// but the marker never holds a value, so it shouldn't have to.
+impl<T: Float + std::fmt::Debug, Space> std::fmt::Debug for TypedVector3<T, Space> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedVector3")
+            .field("vector", &self.vector)
+            .finish()
+    }
+}
+
+impl<T: Float, Space> Copy for TypedVector3<T, Space> {}
+
+impl<T: Float, Space> Clone for TypedVector3<T, Space> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Float, Space> PartialEq for TypedVector3<T, Space> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vector == other.vector
+    }
+}
+
+impl<T: Float, Space> TypedVector3<T, Space> {
+    /// Tags an untyped vector as belonging to `Space`.
+    pub fn new(vector: Vector3<T>) -> Self {
+        Self {
+            vector,
+            _space: PhantomData,
+        }
+    }
+
+    /// Reinterprets `self` as belonging to `NewSpace`.
+    ///
+    /// This is the deliberate escape hatch: the point of this type is to
+    /// make that reinterpretation explicit at the call site instead of
+    /// letting it happen implicitly.
+    pub fn cast_space<NewSpace>(self) -> TypedVector3<T, NewSpace> {
+        TypedVector3::new(self.vector)
+    }
+}
+
+impl<T: Float, Space> From<Vector3<T>> for TypedVector3<T, Space> {
+    fn from(vector: Vector3<T>) -> Self {
+        Self::new(vector)
+    }
+}
+
+impl<T: Float, Space> From<TypedVector3<T, Space>> for Vector3<T> {
+    fn from(typed: TypedVector3<T, Space>) -> Self {
+        typed.vector
+    }
+}
+
+impl<T: Float, Space> Neg for TypedVector3<T, Space> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.vector)
+    }
+}
+
+impl<T: Float, Space> Add for TypedVector3<T, Space> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.vector + rhs.vector)
+    }
+}
+
+impl<T: Float, Space> Sub for TypedVector3<T, Space> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.vector - rhs.vector)
+    }
+}
+
+impl<T: Float, Space> Div<T> for TypedVector3<T, Space> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.vector / rhs)
+    }
+}
+
+impl<T: Float, Space> Dot<T> for TypedVector3<T, Space> {
+    fn dot(&self, other: &Self) -> T {
+        self.vector.dot(&other.vector)
+    }
+}
+
+impl<T: Float, Space> Cross3D for TypedVector3<T, Space> {
+    fn cross(&self, other: &Self) -> Self {
+        Self::new(self.vector.cross(&other.vector))
+    }
+}
+
+impl<T: Float + Ops, Space> Magnitude<T> for TypedVector3<T, Space> {
+    fn magnitude(&self) -> T {
+        self.vector.magnitude()
+    }
+
+    fn quick_magnitude(&self) -> T {
+        self.vector.quick_magnitude()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct World;
+    struct Local;
+
+    fn v(x: f32, y: f32, z: f32) -> TypedVector3<f32, World> {
+        TypedVector3::new(Vector3 { x, y, z })
+    }
+
+    #[test]
+    pub fn add_sub_neg_div_delegate_to_the_inner_vector() {
+        let a = v(1.0, 2.0, 3.0);
+        let b = v(4.0, 5.0, 6.0);
+
+        assert_eq!((a + b).vector, a.vector + b.vector);
+        assert_eq!((a - b).vector, a.vector - b.vector);
+        assert_eq!((-a).vector, -a.vector);
+        assert_eq!((a / 2.0).vector, a.vector / 2.0);
+    }
+
+    #[test]
+    pub fn dot_cross_magnitude_delegate_to_the_inner_vector() {
+        let a = v(1.0, 0.0, 0.0);
+        let b = v(0.0, 1.0, 0.0);
+
+        assert_eq!(a.dot(&b), a.vector.dot(&b.vector));
+        assert_eq!(a.cross(&b).vector, a.vector.cross(&b.vector));
+        assert_eq!(a.magnitude(), a.vector.magnitude());
+    }
+
+    #[test]
+    pub fn cast_space_round_trips_the_inner_vector_unchanged() {
+        let world = v(1.0, 2.0, 3.0);
+
+        let local: TypedVector3<f32, Local> = world.cast_space();
+        let back: TypedVector3<f32, World> = local.cast_space();
+
+        assert_eq!(back.vector, world.vector);
+    }
+}
@@ -5,6 +5,7 @@ use std::{
 
 use num_traits::Float;
 
+use crate::ops::Ops;
 use crate::traits::{Dot, Magnitude, Positional};
 
 use super::{cylindrical::Cylindrical, spherical::Spherical};
@@ -87,9 +88,9 @@ macro_rules! impl_3d {
 
 impl_3d!(f32, f64);
 
-impl<T: Float> crate::traits::Magnitude<T> for Vector3<T> {
+impl<T: Float + Ops> crate::traits::Magnitude<T> for Vector3<T> {
     fn magnitude(&self) -> T {
-        self.quick_magnitude().sqrt()
+        self.quick_magnitude().ops_sqrt()
     }
 
     fn quick_magnitude(&self) -> T {
@@ -108,14 +109,14 @@ impl<T: Float> crate::traits::Cross3D for Vector3<T> {
         Self {
             x: self.y * other.z - self.z * other.y,
             y: self.z * other.x - self.x * other.z,
-            z: self.x * other.y - self.y - other.x,
+            z: self.x * other.y - self.y * other.x,
         }
     }
 }
 
-impl<T: Float> Positional<T> for Vector3<T> {
+impl<T: Float + Ops> Positional<T> for Vector3<T> {
     fn angle_to(&self, other: &Self) -> T {
-        (self.dot(&other) / (self.magnitude() * other.magnitude())).acos()
+        (self.dot(&other) / (self.magnitude() * other.magnitude())).ops_acos()
     }
 }
 
@@ -171,6 +172,44 @@ impl<T: Float> std::ops::Div<T> for Vector3<T> {
     }
 }
 
+impl<T: Float> std::ops::Mul<T> for Vector3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+/*********************
+ * INNER SPACE TRAIT *
+ *********************/
+
+impl<T: Float + Ops> crate::traits::InnerSpace<T> for Vector3<T> {
+    fn project_on(&self, onto: &Self) -> Self {
+        *onto * (self.dot(onto) / onto.dot(onto))
+    }
+
+    fn reject_on(&self, onto: &Self) -> Self {
+        *self - self.project_on(onto)
+    }
+
+    fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * (T::from(2).unwrap() * self.dot(normal))
+    }
+
+    fn normalized(&self) -> Self {
+        *self / self.magnitude()
+    }
+
+    fn lerp(&self, other: &Self, t: T) -> Self {
+        *self + (*other - *self) * t
+    }
+}
+
 /********************
  * FROM DEFINITIONS *
  ********************/
@@ -197,9 +236,9 @@ impl<T: Float> Into<[T; 3]> for Vector3<T> {
     }
 }
 
-impl<T: Float> From<Cylindrical<T>> for Vector3<T> {
+impl<T: Float + Ops> From<Cylindrical<T>> for Vector3<T> {
     fn from(cyl: Cylindrical<T>) -> Self {
-        let (sin, cos) = cyl.azimuth.sin_cos();
+        let (sin, cos) = cyl.azimuth.ops_sin_cos();
         Vector3 {
             x: cyl.radius * cos,
             //FIXME may be off by as much as `8.742278e-8` when `azimuth` == `pi`
@@ -210,9 +249,9 @@ impl<T: Float> From<Cylindrical<T>> for Vector3<T> {
     }
 }
 
-impl<T: Float> From<&Cylindrical<T>> for Vector3<T> {
+impl<T: Float + Ops> From<&Cylindrical<T>> for Vector3<T> {
     fn from(cyl: &Cylindrical<T>) -> Self {
-        let (sin, cos) = cyl.azimuth.sin_cos();
+        let (sin, cos) = cyl.azimuth.ops_sin_cos();
         Vector3 {
             x: cyl.radius * cos,
             y: cyl.radius * sin,
@@ -221,18 +260,18 @@ impl<T: Float> From<&Cylindrical<T>> for Vector3<T> {
     }
 }
 
-impl<T: Float> From<Spherical<T>> for Vector3<T> {
+impl<T: Float + Ops> From<Spherical<T>> for Vector3<T> {
     fn from(sph: Spherical<T>) -> Self {
         Self::from(&sph)
     }
 }
 
-impl<T: Float> From<&Spherical<T>> for Vector3<T> {
+impl<T: Float + Ops> From<&Spherical<T>> for Vector3<T> {
     fn from(sph: &Spherical<T>) -> Self {
         // Sin and cos for the azimuthal angle (0, 1) for straight right (positive x direction)
-        let (sin_az, cos_az) = sph.azimuthal_angle.sin_cos();
+        let (sin_az, cos_az) = sph.azimuthal_angle.ops_sin_cos();
         // Sin and cos relative to the polar angle (0, 1) for straight up
-        let (sin_pol, cos_pol) = sph.polar_angle.sin_cos();
+        let (sin_pol, cos_pol) = sph.polar_angle.ops_sin_cos();
         Vector3 {
             // x = r \times \sin\left(\theta\right) \times \cos\left(\phi\right)
             x: sph.radius * sin_pol * cos_az,
@@ -254,8 +293,79 @@ impl<T: Float + Display> Display for Vector3<T> {
     }
 }
 
+/*************************
+ * APPROXIMATE EQUALITY *
+ *************************/
+
+impl<T: Float + crate::approx_eq::ApproxEq<Eps = T>> crate::approx_eq::ApproxEq for Vector3<T> {
+    type Eps = T;
+
+    fn approx_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.x.approx_eq(&other.x, epsilon, max_relative)
+            && self.y.approx_eq(&other.y, epsilon, max_relative)
+            && self.z.approx_eq(&other.z, epsilon, max_relative)
+    }
+
+    fn approx_eq_ulps(&self, other: &Self, max_ulps: u32) -> bool {
+        self.x.approx_eq_ulps(&other.x, max_ulps)
+            && self.y.approx_eq_ulps(&other.y, max_ulps)
+            && self.z.approx_eq_ulps(&other.z, max_ulps)
+    }
+}
+
+/*****************************
+ * HIGH PRECISION CONVERSION *
+ *****************************/
+
+#[cfg(feature = "high-precision")]
+impl Vector3<f32> {
+    /// Converts `sph` to cartesian the same way `From<Spherical<f32>>`
+    /// does, but widens the radius and angles to `f64` and evaluates the
+    /// trig and multiplications there before narrowing back to `f32`,
+    /// cutting the rounding error the `f32` path would otherwise
+    /// accumulate across the extra multiplications. The plain `From`
+    /// impl is left untouched for performance-sensitive callers.
+    pub fn from_spherical_precise(sph: Spherical<f32>) -> Self {
+        let wide = Spherical {
+            radius: f64::from(sph.radius),
+            azimuthal_angle: f64::from(sph.azimuthal_angle),
+            polar_angle: f64::from(sph.polar_angle),
+        };
+        let precise = Vector3::<f64>::from(wide);
+        Vector3 {
+            x: precise.x as f32,
+            y: precise.y as f32,
+            z: precise.z as f32,
+        }
+    }
+
+    /// Converts `cyl` to cartesian the same way `From<Cylindrical<f32>>`
+    /// does, but widens the radius and azimuth to `f64` and evaluates the
+    /// trig and multiplication there before narrowing back to `f32`,
+    /// cutting the rounding error the `f32` path would otherwise
+    /// accumulate. It does not remove the ~8.7e-8 offset called out on
+    /// `From<Cylindrical>` at `azimuth == pi`: `sin(f32::PI)` is about
+    /// `-8.7e-8` in `f64` as well, so both paths give the same result
+    /// there. The plain `From` impl is left untouched for
+    /// performance-sensitive callers.
+    pub fn from_cylindrical_precise(cyl: Cylindrical<f32>) -> Self {
+        let wide = Cylindrical {
+            radius: f64::from(cyl.radius),
+            azimuth: f64::from(cyl.azimuth),
+            height: f64::from(cyl.height),
+        };
+        let precise = Vector3::<f64>::from(wide);
+        Vector3 {
+            x: precise.x as f32,
+            y: precise.y as f32,
+            z: precise.z as f32,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::approx_eq::ApproxEq;
     use crate::three_dimensional::ThreeDimensionalConsts;
     use crate::traits::Dot;
     use crate::traits::Positional;
@@ -263,7 +373,6 @@ mod tests {
 
     use super::Vector3;
 
-    use assert_float_eq::*;
     use std::f32::EPSILON;
     #[test]
     pub fn is_positional() {
@@ -282,10 +391,264 @@ mod tests {
                 up.angle_to(&point),
                 up.dot(&point)
             );
-            
-            assert_float_relative_eq!(f32::FRAC_PI_2, up.angle_to(&point), EPSILON);
+
+            assert!(f32::FRAC_PI_2.approx_eq(&up.angle_to(&point), EPSILON, EPSILON));
         }
 
-        assert_float_relative_eq!(f32::PI, up.angle_to(&Vector3::<f32>::DOWN), EPSILON);
+        assert!(f32::PI.approx_eq(&up.angle_to(&Vector3::<f32>::DOWN), EPSILON, EPSILON));
+    }
+
+    #[test]
+    pub fn cross_product_right_hand_rule() {
+        use crate::traits::Cross3D;
+
+        assert_eq!(
+            Vector3::<f32>::RIGHT.cross(&Vector3::<f32>::FORWARD),
+            Vector3::<f32>::UP
+        );
+    }
+
+    #[test]
+    pub fn cylindrical_round_trip_near_azimuth_pi() {
+        use crate::three_dimensional::cylindrical::Cylindrical;
+
+        // Large radius (on the order of the Earth's) so the ~8.7e-8 rad
+        // error noted on `From<Cylindrical>` shows up as a real, if
+        // small, absolute error rather than disappearing in the noise.
+        let original = Cylindrical {
+            radius: 6_371_000.0_f32,
+            azimuth: f32::PI,
+            height: 12.0,
+        };
+
+        let recovered = Cylindrical::from(Vector3::from(original));
+
+        assert!(original.radius.approx_eq(&recovered.radius, 1.0, 1e-6));
+        assert!(original.height.approx_eq(&recovered.height, 1.0, 1e-6));
+
+        // `atan2` returns results in `(-pi, pi]`, so a slightly negative
+        // `y` near the branch cut can recover `azimuth ≈ -pi` instead of
+        // `pi` even though it's the same physical angle; `ApproxEq` does
+        // no angle-wraparound normalization, so compare `sin`/`cos`
+        // rather than the raw angle difference.
+        assert!(original
+            .azimuth
+            .sin()
+            .approx_eq(&recovered.azimuth.sin(), 1e-6, 1e-6));
+        assert!(original
+            .azimuth
+            .cos()
+            .approx_eq(&recovered.azimuth.cos(), 1e-6, 1e-6));
+    }
+
+    #[test]
+    pub fn cartesian_to_cylindrical_basic() {
+        use crate::three_dimensional::cylindrical::Cylindrical;
+
+        let cyl = Cylindrical::from(Vector3::<f32> {
+            x: 3.0,
+            y: 4.0,
+            z: 5.0,
+        });
+
+        assert!(cyl.radius.approx_eq(&5.0, EPSILON, EPSILON));
+        assert!(cyl.azimuth.approx_eq(&4.0_f32.atan2(3.0), EPSILON, EPSILON));
+        assert_eq!(cyl.height, 5.0);
+    }
+
+    #[test]
+    pub fn cylindrical_from_origin_is_degenerate() {
+        use crate::three_dimensional::cylindrical::Cylindrical;
+
+        let cyl = Cylindrical::from(Vector3::<f32>::ORIGIN);
+
+        assert_eq!(cyl.radius, 0.0);
+        // `atan2(0, 0)` is conventionally `0`, not `NaN`.
+        assert_eq!(cyl.azimuth, 0.0);
+        assert_eq!(cyl.height, 0.0);
+    }
+
+    #[test]
+    pub fn cartesian_to_spherical_basic() {
+        use crate::three_dimensional::spherical::Spherical;
+
+        let sph = Spherical::from(Vector3::<f32> {
+            x: 3.0,
+            y: 4.0,
+            z: 0.0,
+        });
+
+        assert!(sph.radius.approx_eq(&5.0, EPSILON, EPSILON));
+        assert!(sph
+            .azimuthal_angle
+            .approx_eq(&4.0_f32.atan2(3.0), EPSILON, EPSILON));
+        assert!(sph.polar_angle.approx_eq(&f32::FRAC_PI_2, EPSILON, EPSILON));
+    }
+
+    #[test]
+    pub fn spherical_from_origin_polar_angle_is_nan() {
+        use crate::three_dimensional::spherical::Spherical;
+
+        let sph = Spherical::from(Vector3::<f32>::ORIGIN);
+
+        assert_eq!(sph.radius, 0.0);
+        assert!(sph.polar_angle.is_nan());
+    }
+
+    #[test]
+    pub fn spherical_round_trip() {
+        use crate::three_dimensional::spherical::Spherical;
+
+        let original = Spherical {
+            radius: 10.0_f32,
+            azimuthal_angle: 1.2,
+            polar_angle: 0.7,
+        };
+
+        let recovered = Spherical::from(Vector3::from(original));
+
+        assert!(original.approx_eq(&recovered, 1e-4, 1e-4));
+    }
+
+    #[test]
+    pub fn approx_eq_ulps_handles_sign_flip_near_zero() {
+        // One ULP to either side of zero: bit-distinct, same magnitude,
+        // opposite sign, so the ULP distance across the sign boundary
+        // must come out to 2, not 0.
+        let smallest_positive = f32::from_bits(1);
+        let smallest_negative = f32::from_bits(0x8000_0001);
+
+        assert!(smallest_positive.approx_eq_ulps(&smallest_negative, 2));
+        assert!(!smallest_positive.approx_eq_ulps(&smallest_negative, 1));
+    }
+
+    #[test]
+    pub fn reflect_off_unit_normal() {
+        use crate::traits::InnerSpace;
+
+        let incoming = Vector3 {
+            x: 1.0,
+            y: -1.0,
+            z: 0.0,
+        };
+        let normal = Vector3 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+
+        assert_eq!(
+            incoming.reflect(&normal),
+            Vector3 {
+                x: 1.0,
+                y: 1.0,
+                z: 0.0
+            }
+        );
+    }
+
+    #[test]
+    pub fn reject_on_is_orthogonal_to_onto() {
+        use crate::traits::InnerSpace;
+
+        let v = Vector3::<f32> {
+            x: 3.0,
+            y: 4.0,
+            z: 0.0,
+        };
+        let onto = Vector3::<f32> {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        };
+
+        let rejected = v.reject_on(&onto);
+
+        assert!(rejected.dot(&onto).approx_eq(&0.0, EPSILON, EPSILON));
+    }
+
+    #[test]
+    pub fn lerp_halfway_is_the_midpoint() {
+        use crate::traits::InnerSpace;
+
+        let a = Vector3::<f32>::ORIGIN;
+        let b = Vector3 {
+            x: 2.0,
+            y: 4.0,
+            z: 6.0,
+        };
+
+        assert_eq!(
+            a.lerp(&b, 0.5),
+            Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "high-precision")]
+    pub fn from_spherical_precise_reduces_error_vs_plain_from() {
+        use crate::three_dimensional::spherical::Spherical;
+
+        // Planetary-scale radius, so the `f32` rounding error is a real,
+        // measurable distance instead of noise. Angles where the plain
+        // `f32` path rounds `x` one ULP away from the wide result; at
+        // `azimuth == pi`, `polar == pi / 2` both paths agree exactly.
+        let sph = Spherical {
+            radius: 6_371_000.0_f32,
+            azimuthal_angle: 1.0,
+            polar_angle: 1.0,
+        };
+
+        let plain = Vector3::from(sph);
+        let precise = Vector3::from_spherical_precise(sph);
+
+        let wide = Spherical {
+            radius: f64::from(sph.radius),
+            azimuthal_angle: f64::from(sph.azimuthal_angle),
+            polar_angle: f64::from(sph.polar_angle),
+        };
+        let truth = Vector3::<f64>::from(wide);
+
+        let plain_error =
+            (f64::from(plain.x) - truth.x).abs() + (f64::from(plain.y) - truth.y).abs();
+        let precise_error =
+            (f64::from(precise.x) - truth.x).abs() + (f64::from(precise.y) - truth.y).abs();
+
+        assert!(precise_error < plain_error);
+    }
+
+    #[test]
+    #[cfg(feature = "high-precision")]
+    pub fn from_cylindrical_precise_reduces_error_vs_plain_from() {
+        use crate::three_dimensional::cylindrical::Cylindrical;
+
+        // At `azimuth == pi` both paths agree exactly, so use an azimuth
+        // where the plain `f32` path rounds `x` one ULP away.
+        let cyl = Cylindrical {
+            radius: 6_371_000.0_f32,
+            azimuth: 1.0,
+            height: 12.0,
+        };
+
+        let plain = Vector3::from(cyl);
+        let precise = Vector3::from_cylindrical_precise(cyl);
+
+        let wide = Cylindrical {
+            radius: f64::from(cyl.radius),
+            azimuth: f64::from(cyl.azimuth),
+            height: f64::from(cyl.height),
+        };
+        let truth = Vector3::<f64>::from(wide);
+
+        let plain_error =
+            (f64::from(plain.x) - truth.x).abs() + (f64::from(plain.y) - truth.y).abs();
+        let precise_error =
+            (f64::from(precise.x) - truth.x).abs() + (f64::from(precise.y) - truth.y).abs();
+
+        assert!(precise_error < plain_error);
     }
 }
@@ -0,0 +1,15 @@
+/// Inner-product-space operations built on top of [`Dot`] and [`Magnitude`],
+/// in the spirit of cgmath's `InnerSpace`.
+pub trait InnerSpace<T>: Sized {
+    /// The component of `self` that lies along `onto`.
+    fn project_on(&self, onto: &Self) -> Self;
+    /// The component of `self` orthogonal to `onto`, i.e. `self - self.project_on(onto)`.
+    fn reject_on(&self, onto: &Self) -> Self;
+    /// Reflects `self` off a surface with the given (unit-length) `normal`.
+    fn reflect(&self, normal: &Self) -> Self;
+    /// `self` scaled to unit length.
+    fn normalized(&self) -> Self;
+    /// Linear interpolation between `self` and `other`, where `t == 0` is `self`
+    /// and `t == 1` is `other`.
+    fn lerp(&self, other: &Self, t: T) -> Self;
+}